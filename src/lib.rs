@@ -3,6 +3,23 @@ use std::fmt;
 use thiserror::Error;
 use ureq;
 
+/// Minimal hex codec for the raw calldata the `multicall` module builds
+/// and sends through Etherscan's JSON-RPC proxy; kept in-house rather
+/// than pulling in a dependency for a handful of bytes.
+mod hex {
+    pub fn decode(s: &str) -> Vec<u8> {
+        let clean = s.strip_prefix("0x").unwrap_or(s);
+        (0..clean.len())
+            .step_by(2)
+            .filter_map(|i| u8::from_str_radix(clean.get(i..i + 2)?, 16).ok())
+            .collect()
+    }
+
+    pub fn encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
 pub mod eth {
     use super::*;
 
@@ -16,10 +33,16 @@ pub mod eth {
 
         #[error("Address {0} is not a contract")]
         NotAContract(String),
+
+        #[error("Not a valid tx hash: {0} is not prefixed by 0x")]
+        TxHashNotPrefixed(String),
+
+        #[error("Not a valid tx hash: {0} is not exactly 66 in length. Got: {1}")]
+        TxHashIncorrectLength(String, usize),
     }
 
     /// An address is a simple 42-byte identification for an account
-    #[derive(Debug)]
+    #[derive(Debug, Clone)]
     pub struct Address(String);
 
     impl TryFrom<&str> for Address {
@@ -54,6 +77,51 @@ pub mod eth {
         }
     }
 
+    /// A transaction hash: a 32-byte, 0x-prefixed identifier for a
+    /// transaction. Deserializes straight from the bare hex string
+    /// Etherscan returns, validating it along the way.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(try_from = "String", into = "String")]
+    pub struct TxHash(String);
+
+    impl From<TxHash> for String {
+        fn from(hash: TxHash) -> String {
+            hash.0
+        }
+    }
+
+    impl TryFrom<&str> for TxHash {
+        type Error = Errors;
+
+        fn try_from(hash: &str) -> Result<TxHash, Errors> {
+            hash.to_owned().try_into()
+        }
+    }
+
+    impl TryFrom<String> for TxHash {
+        type Error = Errors;
+
+        fn try_from(hash: String) -> Result<TxHash, Errors> {
+            if !hash.starts_with("0x") {
+                return Err(Errors::TxHashNotPrefixed(hash));
+            }
+
+            let len = hash.len();
+            if len != 66 {
+                return Err(Errors::TxHashIncorrectLength(hash, len));
+            }
+
+            Ok(TxHash(hash))
+        }
+    }
+
+    impl fmt::Display for TxHash {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.0)?;
+            Ok(())
+        }
+    }
+
     /// Account has data associated with an Ethereum account.
     pub struct Account {
         address: Address,
@@ -91,7 +159,7 @@ pub mod eth {
         }
 
         pub fn is_eoa(&self) -> bool {
-            self.code.len() == 0
+            self.code.is_empty()
         }
     }
 
@@ -100,6 +168,7 @@ pub mod eth {
         name: Option<String>,
         abi: Option<String>,
         source: Option<String>,
+        source_tree: Option<source_tree::SourceTree>,
         bytecode: String,
     }
 
@@ -108,10 +177,11 @@ pub mod eth {
             account: eth::Account,
             bytecode: String,
             source: Option<String>,
+            source_tree: Option<source_tree::SourceTree>,
             abi: Option<String>,
             name: Option<String>,
         ) -> Result<Contract, Errors> {
-            if bytecode.len() == 0 {
+            if bytecode.is_empty() {
                 return Err(Errors::NotAContract(account.to_string()));
             };
 
@@ -120,18 +190,49 @@ pub mod eth {
                 name,
                 bytecode,
                 source,
+                source_tree,
                 abi,
             })
         }
+
+        /// Builds a `Contract` from a fetched `etherscan::SourceCode`,
+        /// parsing it into a `source_tree::SourceTree` when it's
+        /// multi-file standard-JSON input rather than a flat file. This
+        /// is the usual construction path: it saves callers from having
+        /// to invoke `source_tree::parse` by hand.
+        pub fn from_source_code(
+            account: eth::Account,
+            bytecode: String,
+            abi: Option<String>,
+            name: Option<String>,
+            source_code: Option<etherscan::SourceCode>,
+        ) -> Result<Contract, Errors> {
+            let (source, source_tree) = match &source_code {
+                Some(code) => (Some(code.raw_source().to_owned()), source_tree::parse(code)),
+                None => (None, None),
+            };
+
+            Contract::new(account, bytecode, source, source_tree, abi, name)
+        }
+
+        /// The parsed multi-file source tree, if the verified source was
+        /// Solidity standard-JSON input rather than a flat file.
+        pub fn source_tree(&self) -> Option<&source_tree::SourceTree> {
+            self.source_tree.as_ref()
+        }
     }
 }
 
 pub mod etherscan {
     use super::*;
     use std::io;
+    use std::io::Write;
+    use std::path::PathBuf;
     use std::{fmt::Error, time::Duration};
+    use std::{fs, time::SystemTime, time::UNIX_EPOCH};
 
     use ureq;
+    use url::Url;
 
     use super::eth;
 
@@ -139,11 +240,228 @@ pub mod etherscan {
 
     #[derive(Error, Debug)]
     pub enum Errors {
+        // Boxed: ureq::Error is large enough (it carries the full
+        // request/response context) that an unboxed variant would bloat
+        // every `Result<_, Errors>` return, tripping clippy's
+        // `result_large_err`.
         #[error("HTTP connection error: ")]
-        HTTPError(#[from] ureq::Error),
+        HTTPError(Box<ureq::Error>),
 
         #[error("JSON error")]
         JSONError(#[from] io::Error),
+
+        #[error("Unsupported chain: {0}")]
+        UnsupportedChain(String),
+
+        #[error("Client builder is missing required field: {0}")]
+        Builder(String),
+
+        #[error("Blocked by Cloudflare")]
+        BlockedByCloudflare,
+
+        #[error("Etherscan rate limit exceeded")]
+        RateLimitExceeded,
+    }
+
+    impl From<ureq::Error> for Errors {
+        fn from(e: ureq::Error) -> Errors {
+            Errors::HTTPError(Box::new(e))
+        }
+    }
+
+    /// Etherscan's Cloudflare-fronted API occasionally returns an HTML
+    /// security-challenge page instead of JSON. Detect the markers it
+    /// uses so callers get a typed error instead of a serde failure.
+    fn is_blocked_by_cloudflare(body: &str) -> bool {
+        body.contains("Attention Required!") || body.contains("cf-browser-verification")
+    }
+
+    /// Checks a parsed Etherscan response body for the rate-limit
+    /// signature (`status == "0"` plus a "rate limit" message/result),
+    /// without committing to the typed `Response<T>` shape first — the
+    /// rate-limit payload's `result` is a string, not the usual array.
+    fn is_rate_limited(value: &serde_json::Value) -> bool {
+        if value.get("status").and_then(|s| s.as_str()) != Some("0") {
+            return false;
+        }
+
+        let mentions_rate_limit = |field: &str| {
+            value
+                .get(field)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_lowercase().contains("rate limit"))
+                .unwrap_or(false)
+        };
+
+        mentions_rate_limit("result") || mentions_rate_limit("message")
+    }
+
+    /// The EVM chain (or EVM-compatible sidechain) an `etherscan::Client`
+    /// talks to. Each variant derives both the JSON API endpoint and the
+    /// human-facing explorer URL used to build requests and links.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Chain {
+        Mainnet,
+        Ropsten,
+        Kovan,
+        Rinkeby,
+        Goerli,
+        Polygon,
+        Bsc,
+        Arbitrum,
+        Optimism,
+    }
+
+    impl Chain {
+        fn slug(&self) -> &'static str {
+            match self {
+                Chain::Mainnet => "mainnet",
+                Chain::Ropsten => "ropsten",
+                Chain::Kovan => "kovan",
+                Chain::Rinkeby => "rinkeby",
+                Chain::Goerli => "goerli",
+                Chain::Polygon => "polygon",
+                Chain::Bsc => "bsc",
+                Chain::Arbitrum => "arbitrum",
+                Chain::Optimism => "optimism",
+            }
+        }
+
+        /// The JSON API endpoint for this chain. The Etherscan-family
+        /// testnets all share `etherscan.io` behind an `api-{slug}.`
+        /// subdomain, so those are still derived from `slug()`; the
+        /// L2/sidechain variants live on entirely different explorer
+        /// domains (Polygonscan, BscScan, Arbiscan, Optimistic
+        /// Etherscan) and are looked up here directly rather than
+        /// forced through the same format string.
+        fn api_url(&self) -> Url {
+            let url = match self {
+                Chain::Mainnet => ETHERSCAN_URL.to_owned(),
+                Chain::Ropsten | Chain::Kovan | Chain::Rinkeby | Chain::Goerli => {
+                    format!("https://api-{}.etherscan.io/api", self.slug())
+                }
+                Chain::Polygon => "https://api.polygonscan.com/api".to_owned(),
+                Chain::Bsc => "https://api.bscscan.com/api".to_owned(),
+                Chain::Arbitrum => "https://api.arbiscan.io/api".to_owned(),
+                Chain::Optimism => "https://api-optimistic.etherscan.io/api".to_owned(),
+            };
+            Url::parse(&url).expect("chain API URL is always valid")
+        }
+
+        /// The human-facing explorer URL used to browse an address, e.g.
+        /// `https://ropsten.etherscan.io/address` or
+        /// `https://polygonscan.com/address`. See `api_url` for why the
+        /// L2/sidechain variants can't share its format string.
+        fn base_url(&self) -> Url {
+            let url = match self {
+                Chain::Mainnet => "https://etherscan.io/address".to_owned(),
+                Chain::Ropsten | Chain::Kovan | Chain::Rinkeby | Chain::Goerli => {
+                    format!("https://{}.etherscan.io/address", self.slug())
+                }
+                Chain::Polygon => "https://polygonscan.com/address".to_owned(),
+                Chain::Bsc => "https://bscscan.com/address".to_owned(),
+                Chain::Arbitrum => "https://arbiscan.io/address".to_owned(),
+                Chain::Optimism => "https://optimistic.etherscan.io/address".to_owned(),
+            };
+            Url::parse(&url).expect("chain base URL is always valid")
+        }
+    }
+
+    impl TryFrom<&str> for Chain {
+        type Error = Errors;
+
+        fn try_from(name: &str) -> Result<Chain, Errors> {
+            match name {
+                "mainnet" => Ok(Chain::Mainnet),
+                "ropsten" => Ok(Chain::Ropsten),
+                "kovan" => Ok(Chain::Kovan),
+                "rinkeby" => Ok(Chain::Rinkeby),
+                "goerli" => Ok(Chain::Goerli),
+                "polygon" => Ok(Chain::Polygon),
+                "bsc" => Ok(Chain::Bsc),
+                "arbitrum" => Ok(Chain::Arbitrum),
+                "optimism" => Ok(Chain::Optimism),
+                other => Err(Errors::UnsupportedChain(other.to_owned())),
+            }
+        }
+    }
+
+    fn now_secs() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is after the UNIX epoch")
+            .as_secs()
+    }
+
+    /// Wraps a cached value with the UNIX timestamp at which it expires.
+    #[derive(Serialize, Deserialize, Debug)]
+    struct CacheEnvelope<T> {
+        expiry: u64,
+        data: T,
+    }
+
+    /// A file-backed cache for Etherscan responses, keyed by address and
+    /// kept fresh with a time-to-live. A cached entry may itself be
+    /// `None`, which lets us remember "this address is unverified"
+    /// without refetching it on every run.
+    struct Cache {
+        root: PathBuf,
+        ttl: Duration,
+    }
+
+    impl Cache {
+        fn new(root: PathBuf, ttl: Duration) -> Cache {
+            Cache { root, ttl }
+        }
+
+        fn path_for(&self, kind: &str, addr: &eth::Address) -> PathBuf {
+            self.root.join(kind).join(format!("{}.json", addr))
+        }
+
+        /// Looks up a cached value for `addr`. Returns `None` on a cache
+        /// miss or an expired entry; `Some(None)` if `addr` was cached as
+        /// having no data (e.g. an unverified contract); `Some(Some(data))`
+        /// otherwise.
+        fn get<T: for<'a> Deserialize<'a>>(
+            &self,
+            kind: &str,
+            addr: &eth::Address,
+        ) -> Option<Option<T>> {
+            let contents = fs::read_to_string(self.path_for(kind, addr)).ok()?;
+            let envelope: CacheEnvelope<Option<T>> = serde_json::from_str(&contents).ok()?;
+
+            if envelope.expiry <= now_secs() {
+                return None;
+            }
+
+            Some(envelope.data)
+        }
+
+        /// Writes `data` (which may be `None` to cache a negative result)
+        /// back to disk under `kind`, flushing the writer.
+        fn put<T: Serialize>(
+            &self,
+            kind: &str,
+            addr: &eth::Address,
+            data: &Option<T>,
+        ) -> io::Result<()> {
+            let path = self.path_for(kind, addr);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+
+            let envelope = CacheEnvelope {
+                expiry: now_secs() + self.ttl.as_secs(),
+                data,
+            };
+
+            let file = fs::File::create(path)?;
+            let mut writer = io::BufWriter::new(file);
+            serde_json::to_writer(&mut writer, &envelope)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            writer.flush()?;
+            Ok(())
+        }
     }
 
     /// The response from the JSON APIs. All of the calls give the same top-level JSON
@@ -165,34 +483,124 @@ pub mod etherscan {
         contract_name: String,
     }
 
+    impl SourceCode {
+        /// The raw `SourceCode` field Etherscan returned: either a flat
+        /// Solidity source or a (possibly double-brace-wrapped)
+        /// standard-JSON blob. See `source_tree::parse`.
+        pub(crate) fn raw_source(&self) -> &str {
+            &self.source
+        }
+    }
+
     pub struct ABI(String);
 
+    /// A single entry from Etherscan's normal or internal transaction
+    /// list for an address.
+    #[derive(Serialize, Deserialize, Debug)]
+    pub struct Transaction {
+        pub hash: eth::TxHash,
+        pub from: String,
+        pub to: String,
+        pub value: String,
+        pub input: String,
+        #[serde(rename = "isError")]
+        pub is_error: String,
+    }
+
+    /// The normal and internal transactions touching an address, used to
+    /// discover which other addresses a call graph should walk next.
+    #[derive(Debug)]
+    pub struct Transactions {
+        pub normal: Vec<Transaction>,
+        pub internal: Vec<Transaction>,
+    }
+
+    /// The creator and creation transaction of a contract, as returned by
+    /// `getcontractcreation`.
+    #[derive(Serialize, Deserialize, Debug)]
+    pub struct ContractCreation {
+        #[serde(rename = "contractAddress")]
+        pub contract_address: String,
+        #[serde(rename = "contractCreator")]
+        pub creator: String,
+        #[serde(rename = "txHash")]
+        pub tx_hash: eth::TxHash,
+    }
+
     /// A client to interact with Etherescan
     pub struct Client {
         apikey: String,
-        url: String,
+        chain: Chain,
+        url: Url,
+        base_url: Url,
         http: ureq::Agent,
+        cache: Option<Cache>,
     }
 
     impl Client {
-        /// Creates a new client for the Etherescan API with a given API key
-        /// The default HTTP client has a five-second timeout
-        pub fn new(apikey: String) -> Client {
+        /// Creates a new client for the Etherescan API targeting `chain`
+        /// with a given API key. The default HTTP client has a
+        /// five-second timeout.
+        pub fn new(apikey: String, chain: Chain) -> Client {
             let http = ureq::AgentBuilder::new()
                 .timeout(Duration::from_secs(5))
                 .build();
 
-            Self::new_with_custom_http(apikey, http)
+            Self::new_with_custom_http(apikey, chain, http)
         }
 
-        /// Create a new client for the Etherescan API with a given API key
-        /// plus a custom ureq agent.
+        /// Create a new client for the Etherescan API targeting `chain`
+        /// with a given API key plus a custom ureq agent.
         // It would greate help if anyone could pass their own abstract
         // http client here. That's easy to achieve in Go, but could not find
         // a reasonable way in Rust.
-        pub fn new_with_custom_http(apikey: String, http: ureq::Agent) -> Client {
-            let url = format!("{}?apikey={}", ETHERSCAN_URL, apikey);
-            Client { apikey, url, http }
+        pub fn new_with_custom_http(apikey: String, chain: Chain, http: ureq::Agent) -> Client {
+            let url = Url::parse(&format!("{}?apikey={}", chain.api_url(), apikey))
+                .expect("chain API URL plus apikey is always a valid URL");
+            let base_url = chain.base_url();
+            Client {
+                apikey,
+                chain,
+                url,
+                base_url,
+                http,
+                cache: None,
+            }
+        }
+
+        /// The chain this client was configured to talk to.
+        pub fn chain(&self) -> Chain {
+            self.chain
+        }
+
+        /// Creates a new client targeting `chain` with an on-disk cache
+        /// rooted at `cache_root`, keeping entries fresh for `ttl`.
+        /// Repeated `get_source_code`/`get_abi` lookups for the same
+        /// address are served from disk instead of hitting Etherscan,
+        /// including negative ("unverified") results.
+        pub fn new_cached(
+            apikey: String,
+            chain: Chain,
+            cache_root: PathBuf,
+            ttl: Duration,
+        ) -> Client {
+            let mut client = Self::new(apikey, chain);
+            client.cache = Some(Cache::new(cache_root, ttl));
+            client
+        }
+
+        /// Entry point for configuring combinations of chain, cache, and
+        /// HTTP client that `new`/`new_with_custom_http`/`new_cached`
+        /// can't express together.
+        pub fn builder() -> ClientBuilder {
+            ClientBuilder::new()
+        }
+
+        /// The human-facing explorer URL for browsing `addr` on this
+        /// client's chain.
+        pub fn explorer_url(&self, addr: &eth::Address) -> Url {
+            Url::parse(&format!("{}/{}", self.base_url, addr))
+                .expect("base URL plus address is always a valid URL")
         }
 
         // Weird rust probably incoming?  Higher-ranked trait bounds
@@ -203,26 +611,830 @@ pub mod etherscan {
         // Here we say that for all A, the data the deserializer will have access to
         // will outlive it. No matter the lifetime of the deserializer itself.
         fn get<T: for<'a> Deserialize<'a>>(&self, url: &str) -> Result<Response<T>, Errors> {
-            let res: Response<T> = self.http.get(&url).call()?.into_json()?;
+            let body = self.http.get(&url).call()?.into_string()?;
+
+            if is_blocked_by_cloudflare(&body) {
+                return Err(Errors::BlockedByCloudflare);
+            }
+
+            let value: serde_json::Value = serde_json::from_str(&body)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            if is_rate_limited(&value) {
+                return Err(Errors::RateLimitExceeded);
+            }
+
+            let res: Response<T> = serde_json::from_value(value)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
             Ok(res)
         }
 
-        pub fn get_source_code(&self, addr: &eth::Address) -> Result<Response<SourceCode>, Errors> {
+        /// Fetches the verified source for `addr`, or `None` if it is
+        /// unverified. When the client was built with a cache, a fresh
+        /// entry (including a cached "unverified" result) is served from
+        /// disk instead of calling Etherscan.
+        pub fn get_source_code(&self, addr: &eth::Address) -> Result<Option<SourceCode>, Errors> {
+            const KIND: &str = "sources";
+
+            if let Some(cache) = &self.cache {
+                if let Some(cached) = cache.get(KIND, addr) {
+                    return Ok(cached);
+                }
+            }
+
             let url = format!(
                 "{}/&module=contract&action=getsourcecode&address={}",
                 self.url, addr,
             );
+            let response: Response<SourceCode> = self.get(&url)?;
+            let source = response
+                .result
+                .into_iter()
+                .next()
+                .filter(|sc| !sc.source.is_empty());
+
+            if let Some(cache) = &self.cache {
+                cache.put(KIND, addr, &source)?;
+            }
 
-            self.get(&url)
+            Ok(source)
         }
 
-        pub fn get_abi(&self, addr: &eth::Address) -> Result<Response<String>, Errors> {
+        /// Fetches the ABI for `addr`, or `None` if it is unverified. When
+        /// the client was built with a cache, a fresh entry (including a
+        /// cached "unverified" result) is served from disk instead of
+        /// calling Etherscan.
+        pub fn get_abi(&self, addr: &eth::Address) -> Result<Option<String>, Errors> {
+            const KIND: &str = "abi";
+
+            if let Some(cache) = &self.cache {
+                if let Some(cached) = cache.get(KIND, addr) {
+                    return Ok(cached);
+                }
+            }
+
             let url = format!(
-                "{}/&module=contract&action=getsourcecode&address={}",
+                "{}/&module=contract&action=getabi&address={}",
                 self.url, addr,
             );
 
-            self.get(&url)
+            let body = self.http.get(&url).call()?.into_string()?;
+
+            if is_blocked_by_cloudflare(&body) {
+                return Err(Errors::BlockedByCloudflare);
+            }
+
+            let value: serde_json::Value = serde_json::from_str(&body)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            if is_rate_limited(&value) {
+                return Err(Errors::RateLimitExceeded);
+            }
+
+            // Unverified contracts report `status == "0"` with a
+            // "Contract source code not verified" message, not an error —
+            // same unverified-as-None convention as `get_source_code`.
+            let is_unverified = value.get("status").and_then(|s| s.as_str()) == Some("0");
+            let abi = if is_unverified {
+                None
+            } else {
+                value
+                    .get("result")
+                    .and_then(|r| r.as_str())
+                    .map(|s| s.to_owned())
+                    .filter(|abi| !abi.is_empty())
+            };
+
+            if let Some(cache) = &self.cache {
+                cache.put(KIND, addr, &abi)?;
+            }
+
+            Ok(abi)
         }
+
+        /// Fetches the normal and internal transactions touching `addr`,
+        /// for discovering which other addresses a call graph should
+        /// walk next.
+        pub fn get_transactions(&self, addr: &eth::Address) -> Result<Transactions, Errors> {
+            let normal_url = format!(
+                "{}/&module=account&action=txlist&address={}",
+                self.url, addr,
+            );
+            let internal_url = format!(
+                "{}/&module=account&action=txlistinternal&address={}",
+                self.url, addr,
+            );
+
+            let normal: Response<Transaction> = self.get(&normal_url)?;
+            let internal: Response<Transaction> = self.get(&internal_url)?;
+
+            Ok(Transactions {
+                normal: normal.result,
+                internal: internal.result,
+            })
+        }
+
+        /// Fetches the creator and creation transaction of `addr`, or
+        /// `None` if Etherscan has no creation record for it (e.g. it's
+        /// an EOA or a pre-Byzantium contract).
+        pub fn get_contract_creation(
+            &self,
+            addr: &eth::Address,
+        ) -> Result<Option<ContractCreation>, Errors> {
+            let url = format!(
+                "{}/&module=contract&action=getcontractcreation&contractaddresses={}",
+                self.url, addr,
+            );
+
+            let body = self.http.get(&url).call()?.into_string()?;
+
+            if is_blocked_by_cloudflare(&body) {
+                return Err(Errors::BlockedByCloudflare);
+            }
+
+            let value: serde_json::Value = serde_json::from_str(&body)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            if is_rate_limited(&value) {
+                return Err(Errors::RateLimitExceeded);
+            }
+
+            // Etherscan reports "no creation record" (an EOA, or a
+            // pre-Byzantium contract) as `{"status":"0","result":null}`
+            // rather than an empty array, which `Response<ContractCreation>`
+            // can't deserialize — check for that shape before handing the
+            // value to serde.
+            let result = value.get("result");
+            if result.map_or(true, |r| r.is_null()) {
+                return Ok(None);
+            }
+
+            let response: Response<ContractCreation> = serde_json::from_value(value)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            Ok(response.result.into_iter().next())
+        }
+
+        /// Like `get`, but for endpoints (account balance, the JSON-RPC
+        /// proxy) whose `result` field is a bare JSON value rather than
+        /// the usual `Vec<T>`.
+        fn get_scalar(&self, url: &str) -> Result<serde_json::Value, Errors> {
+            let body = self.http.get(url).call()?.into_string()?;
+
+            if is_blocked_by_cloudflare(&body) {
+                return Err(Errors::BlockedByCloudflare);
+            }
+
+            let value: serde_json::Value = serde_json::from_str(&body)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            if is_rate_limited(&value) {
+                return Err(Errors::RateLimitExceeded);
+            }
+
+            // `account`-module endpoints report failure via `status ==
+            // "0"`; the JSON-RPC proxy reports it via an `error` object.
+            // Catch both here so a failed call can't be mistaken for a
+            // successful (if oddly-shaped) `result`.
+            let failed = value.get("status").and_then(|s| s.as_str()) == Some("0")
+                || value.get("error").is_some();
+            if failed {
+                let message = value
+                    .get("message")
+                    .or_else(|| value.get("error").and_then(|e| e.get("message")))
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("unknown error")
+                    .to_owned();
+                return Err(io::Error::new(io::ErrorKind::Other, message).into());
+            }
+
+            value.get("result").cloned().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing result field").into()
+            })
+        }
+
+        /// Fetches `addr`'s balance in wei via Etherscan's
+        /// `account/balance` endpoint.
+        pub fn get_balance(&self, addr: &eth::Address) -> Result<u128, Errors> {
+            let url = format!(
+                "{}/&module=account&action=balance&address={}&tag=latest",
+                self.url, addr,
+            );
+
+            let result = self.get_scalar(&url)?;
+            result
+                .as_str()
+                .unwrap_or_default()
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+        }
+
+        /// Fetches `addr`'s deployed bytecode via Etherscan's JSON-RPC
+        /// proxy (`eth_getCode`).
+        pub fn get_code(&self, addr: &eth::Address) -> Result<Vec<u8>, Errors> {
+            let url = format!(
+                "{}/&module=proxy&action=eth_getCode&address={}&tag=latest",
+                self.url, addr,
+            );
+
+            let result = self.get_scalar(&url)?;
+            Ok(hex::decode(result.as_str().unwrap_or_default()))
+        }
+
+        /// Issues a raw `eth_call` against `to` through Etherscan's
+        /// JSON-RPC proxy, returning the decoded return data. Used by
+        /// the `multicall` module to batch balance/code lookups.
+        pub(crate) fn eth_call(&self, to: &str, data: &str) -> Result<Vec<u8>, Errors> {
+            let url = format!(
+                "{}/&module=proxy&action=eth_call&to=0x{}&data=0x{}&tag=latest",
+                self.url, to, data,
+            );
+
+            let result = self.get_scalar(&url)?;
+            Ok(hex::decode(result.as_str().unwrap_or_default()))
+        }
+    }
+
+    /// Builder for `Client`, for configuring combinations of chain, cache,
+    /// and HTTP client that the plain constructors can't express.
+    #[derive(Default)]
+    pub struct ClientBuilder {
+        apikey: Option<String>,
+        chain: Option<Chain>,
+        cache: Option<Cache>,
+        http: Option<ureq::Agent>,
+    }
+
+    impl ClientBuilder {
+        fn new() -> ClientBuilder {
+            ClientBuilder::default()
+        }
+
+        pub fn with_api_key(mut self, apikey: String) -> ClientBuilder {
+            self.apikey = Some(apikey);
+            self
+        }
+
+        pub fn chain(mut self, chain: Chain) -> ClientBuilder {
+            self.chain = Some(chain);
+            self
+        }
+
+        pub fn with_cache(mut self, root: PathBuf, ttl: Duration) -> ClientBuilder {
+            self.cache = Some(Cache::new(root, ttl));
+            self
+        }
+
+        pub fn with_client(mut self, http: ureq::Agent) -> ClientBuilder {
+            self.http = Some(http);
+            self
+        }
+
+        /// Validates the builder and produces a `Client`, defaulting to a
+        /// five-second-timeout HTTP client when none was given.
+        pub fn build(self) -> Result<Client, Errors> {
+            let apikey = self
+                .apikey
+                .ok_or_else(|| Errors::Builder("apikey".to_owned()))?;
+            let chain = self
+                .chain
+                .ok_or_else(|| Errors::Builder("chain".to_owned()))?;
+            let http = self.http.unwrap_or_else(|| {
+                ureq::AgentBuilder::new()
+                    .timeout(Duration::from_secs(5))
+                    .build()
+            });
+
+            let mut client = Client::new_with_custom_http(apikey, chain, http);
+            client.cache = self.cache;
+            Ok(client)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn mainnet_and_testnets_stay_on_etherscan_io() {
+            assert_eq!(
+                Chain::Mainnet.api_url().as_str(),
+                "https://api.etherscan.io/api"
+            );
+            assert_eq!(
+                Chain::Mainnet.base_url().as_str(),
+                "https://etherscan.io/address"
+            );
+            assert_eq!(
+                Chain::Ropsten.api_url().as_str(),
+                "https://api-ropsten.etherscan.io/api"
+            );
+            assert_eq!(
+                Chain::Ropsten.base_url().as_str(),
+                "https://ropsten.etherscan.io/address"
+            );
+        }
+
+        #[test]
+        fn l2_and_sidechain_variants_use_their_own_explorer_domain() {
+            assert_eq!(
+                Chain::Polygon.api_url().as_str(),
+                "https://api.polygonscan.com/api"
+            );
+            assert_eq!(
+                Chain::Polygon.base_url().as_str(),
+                "https://polygonscan.com/address"
+            );
+            assert_eq!(Chain::Bsc.api_url().as_str(), "https://api.bscscan.com/api");
+            assert_eq!(
+                Chain::Arbitrum.api_url().as_str(),
+                "https://api.arbiscan.io/api"
+            );
+            assert_eq!(
+                Chain::Optimism.api_url().as_str(),
+                "https://api-optimistic.etherscan.io/api"
+            );
+        }
+
+        fn test_cache() -> Cache {
+            let root = std::env::temp_dir().join(format!(
+                "contract-callgraph-test-cache-{}-{}",
+                std::process::id(),
+                now_secs()
+            ));
+            Cache::new(root, Duration::from_secs(60))
+        }
+
+        fn test_addr() -> eth::Address {
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+                .try_into()
+                .unwrap()
+        }
+
+        #[test]
+        fn cache_round_trips_a_value_and_expires_it() {
+            let cache = test_cache();
+            let addr = test_addr();
+
+            assert_eq!(cache.get::<String>("sources", &addr), None);
+
+            cache
+                .put(
+                    "sources",
+                    &addr,
+                    &Some("pragma solidity ^0.8.0;".to_owned()),
+                )
+                .unwrap();
+            assert_eq!(
+                cache.get::<String>("sources", &addr),
+                Some(Some("pragma solidity ^0.8.0;".to_owned()))
+            );
+
+            // An already-expired entry (zero TTL) is a miss, not `Some(None)`.
+            let expired = Cache::new(cache.root.clone(), Duration::from_secs(0));
+            expired
+                .put(
+                    "sources",
+                    &addr,
+                    &Some("pragma solidity ^0.8.0;".to_owned()),
+                )
+                .unwrap();
+            std::thread::sleep(Duration::from_secs(1));
+            assert_eq!(expired.get::<String>("sources", &addr), None);
+
+            fs::remove_dir_all(&cache.root).ok();
+        }
+
+        #[test]
+        fn cache_stores_negative_results_distinctly_from_misses() {
+            let cache = test_cache();
+            let addr = test_addr();
+
+            cache.put::<String>("abi", &addr, &None).unwrap();
+            assert_eq!(cache.get::<String>("abi", &addr), Some(None));
+
+            fs::remove_dir_all(&cache.root).ok();
+        }
+    }
+}
+
+pub mod source_tree {
+    use super::*;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    use super::etherscan::SourceCode;
+
+    #[derive(Error, Debug)]
+    pub enum Errors {
+        #[error("I/O error writing source tree: {0}")]
+        IOError(#[from] io::Error),
+    }
+
+    /// One file within a multi-file verified contract, as returned by
+    /// Etherscan's standard-JSON input format.
+    #[derive(Debug, Clone)]
+    pub struct SourceTreeEntry {
+        pub path: PathBuf,
+        pub contents: String,
+    }
+
+    /// A parsed, multi-file verified source. Etherscan returns these for
+    /// contracts verified via Solidity standard-JSON input; `write_to`
+    /// reconstructs the original directory layout on disk, which is a
+    /// prerequisite for resolving cross-file `import` statements.
+    #[derive(Debug, Clone)]
+    pub struct SourceTree {
+        pub entries: Vec<SourceTreeEntry>,
+    }
+
+    impl SourceTree {
+        /// Writes every entry back out under `root`, recreating the
+        /// original directory layout.
+        pub fn write_to(&self, root: &Path) -> Result<(), Errors> {
+            for entry in &self.entries {
+                let dest = root.join(&entry.path);
+                if let Some(dir) = dest.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                fs::write(dest, &entry.contents)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct StandardJsonInput {
+        sources: HashMap<String, StandardJsonFile>,
+    }
+
+    #[derive(Deserialize)]
+    struct StandardJsonFile {
+        content: String,
+    }
+
+    /// Parses `source`'s `SourceCode` field into a `SourceTree` if it's
+    /// Solidity standard-JSON input, or returns `None` if it's a flat
+    /// single-file source. Etherscan sometimes wraps the JSON in an
+    /// extra pair of braces (`{{...}}`); that layer is stripped first.
+    pub fn parse(source: &SourceCode) -> Option<SourceTree> {
+        let raw = source.raw_source().trim();
+        if !raw.starts_with('{') {
+            return None;
+        }
+
+        let unwrapped = match raw.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(inner) if inner.trim_start().starts_with('{') => inner,
+            _ => raw,
+        };
+
+        let parsed: StandardJsonInput = serde_json::from_str(unwrapped).ok()?;
+        let entries = parsed
+            .sources
+            .into_iter()
+            .map(|(path, file)| SourceTreeEntry {
+                path: PathBuf::from(path),
+                contents: file.content,
+            })
+            .collect();
+
+        Some(SourceTree { entries })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn source_code(raw: &str) -> SourceCode {
+            serde_json::from_value(serde_json::json!({
+                "SourceCode": raw,
+                "ConstructorArguments": "",
+                "ContractName": "Test",
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn flat_source_is_not_a_tree() {
+            assert!(parse(&source_code("pragma solidity ^0.8.0;\ncontract C {}")).is_none());
+        }
+
+        #[test]
+        fn parses_standard_json_input() {
+            let raw = r#"{"sources": {"contracts/A.sol": {"content": "contract A {}"}}}"#;
+            let tree = parse(&source_code(raw)).expect("standard-JSON input should parse");
+            assert_eq!(tree.entries.len(), 1);
+            assert_eq!(tree.entries[0].path, PathBuf::from("contracts/A.sol"));
+            assert_eq!(tree.entries[0].contents, "contract A {}");
+        }
+
+        #[test]
+        fn strips_one_layer_of_double_brace_wrapping() {
+            let raw = r#"{{"sources": {"contracts/A.sol": {"content": "contract A {}"}}}}"#;
+            let tree = parse(&source_code(raw)).expect("double-brace-wrapped input should parse");
+            assert_eq!(tree.entries.len(), 1);
+            assert_eq!(tree.entries[0].contents, "contract A {}");
+        }
+    }
+}
+
+pub mod multicall {
+    use super::*;
+
+    use super::etherscan::{self, Chain, Errors};
+
+    /// Selector for `aggregate((address,bytes)[] calls) returns
+    /// (uint256 blockNumber, bytes[] returnData)`.
+    const AGGREGATE_SELECTOR: &str = "252dba42";
+    /// Selector for `getEthBalance(address) returns (uint256 balance)`.
+    const GET_ETH_BALANCE_SELECTOR: &str = "4d2301cc";
+
+    /// Canonical Multicall deployment addresses, keyed by chain. A chain
+    /// with no entry here falls back to sequential Etherscan calls.
+    fn multicall_address(chain: Chain) -> Option<&'static str> {
+        match chain {
+            Chain::Mainnet => Some("eefba1e63905ef1d7acba5a8513c70307c1ce441"),
+            Chain::Ropsten => Some("53c43764255c17bd724f74c4ef150724ac50a3ed"),
+            Chain::Kovan => Some("2cc8688c5f75e365aaeeb4ea8d6a480405a48d2a"),
+            Chain::Rinkeby => Some("42ad527de7d4e9d9d011ac45b31d8551f8fe9821"),
+            Chain::Goerli => Some("77dca2c955b15e9de4dbbcf1246b4b85b651e50e"),
+            Chain::Polygon => Some("11ce4b23bd875d7f5c6a31084f55fda1e9a61554"),
+            Chain::Bsc | Chain::Arbitrum | Chain::Optimism => None,
+        }
+    }
+
+    fn pad_left_32(bytes: &[u8]) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        let start = 32 - bytes.len().min(32);
+        word[start..].copy_from_slice(&bytes[bytes.len().saturating_sub(32)..]);
+        word
+    }
+
+    fn encode_address_arg(addr: &eth::Address) -> [u8; 32] {
+        pad_left_32(&hex::decode(&addr.to_string()))
+    }
+
+    /// ABI-encodes a call to `aggregate((address,bytes)[] calls)` for
+    /// `calls`, a list of `(target, calldata)` pairs.
+    fn encode_aggregate(calls: &[(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
+        let n = calls.len();
+
+        // Each (address,bytes) tuple is dynamic (it holds a `bytes`
+        // field), so it's encoded head+tail: address, then the offset
+        // (relative to the tuple's own start) to its bytes' length+data.
+        let tuple_bodies: Vec<Vec<u8>> = calls
+            .iter()
+            .map(|(target, calldata)| {
+                let mut body = Vec::new();
+                body.extend_from_slice(&pad_left_32(target));
+                body.extend_from_slice(&pad_left_32(&64u64.to_be_bytes()));
+                body.extend_from_slice(&pad_left_32(&(calldata.len() as u64).to_be_bytes()));
+                body.extend_from_slice(calldata);
+                let pad = (32 - (calldata.len() % 32)) % 32;
+                body.extend(std::iter::repeat(0u8).take(pad));
+                body
+            })
+            .collect();
+
+        // Every element is dynamic, so the array's head is a list of
+        // offsets (relative to the start of the array's data, i.e. right
+        // after the length word) to each tuple's body.
+        let mut offsets = Vec::with_capacity(n);
+        let mut running = (n * 32) as u64;
+        for body in &tuple_bodies {
+            offsets.push(running);
+            running += body.len() as u64;
+        }
+
+        let mut array_data = Vec::new();
+        array_data.extend_from_slice(&pad_left_32(&(n as u64).to_be_bytes()));
+        for offset in &offsets {
+            array_data.extend_from_slice(&pad_left_32(&offset.to_be_bytes()));
+        }
+        for body in &tuple_bodies {
+            array_data.extend_from_slice(body);
+        }
+
+        let mut calldata = hex::decode(AGGREGATE_SELECTOR);
+        calldata.extend_from_slice(&pad_left_32(&32u64.to_be_bytes()));
+        calldata.extend_from_slice(&array_data);
+        calldata
+    }
+
+    /// Copies out `len` bytes starting at `start`, or an empty vec if
+    /// that range would overflow or run past `data`'s end. Offsets and
+    /// lengths below come straight from an external call's return data,
+    /// so they can't be trusted to be in-bounds.
+    fn safe_slice(data: &[u8], start: usize, len: usize) -> Vec<u8> {
+        match start.checked_add(len) {
+            Some(end) if end <= data.len() => data[start..end].to_vec(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn word_at(data: &[u8], offset: usize) -> u64 {
+        let word = safe_slice(data, offset, 32);
+        if word.len() < 32 {
+            return 0;
+        }
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&word[24..32]);
+        u64::from_be_bytes(buf)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn encode_aggregate_prefixes_the_selector_and_embeds_each_call() {
+            let target = hex::decode("eefba1e63905ef1d7acba5a8513c70307c1ce441");
+            let mut calldata = hex::decode(GET_ETH_BALANCE_SELECTOR);
+            calldata.extend_from_slice(&[0u8; 32]);
+
+            let encoded = encode_aggregate(&[(target.clone(), calldata.clone())]);
+
+            assert_eq!(&encoded[0..4], &hex::decode(AGGREGATE_SELECTOR)[..]);
+            assert!(encoded.windows(target.len()).any(|w| w == target));
+            assert!(encoded
+                .windows(calldata.len())
+                .any(|w| w == calldata.as_slice()));
+        }
+
+        /// Hand-builds an `(uint256, bytes[])` return blob (the shape
+        /// `aggregate` itself returns), for checking that
+        /// `decode_aggregate_result` recovers each `bytes` element untouched.
+        fn encode_bytes_array_result(elements: &[&[u8]]) -> Vec<u8> {
+            let n = elements.len();
+            let bodies: Vec<Vec<u8>> = elements
+                .iter()
+                .map(|bytes| {
+                    let mut body = Vec::new();
+                    body.extend_from_slice(&pad_left_32(&(bytes.len() as u64).to_be_bytes()));
+                    body.extend_from_slice(bytes);
+                    body.extend(std::iter::repeat(0u8).take((32 - (bytes.len() % 32)) % 32));
+                    body
+                })
+                .collect();
+
+            let mut array_data = Vec::new();
+            array_data.extend_from_slice(&pad_left_32(&(n as u64).to_be_bytes()));
+            let mut running = (n * 32) as u64;
+            for body in &bodies {
+                array_data.extend_from_slice(&pad_left_32(&running.to_be_bytes()));
+                running += body.len() as u64;
+            }
+            for body in &bodies {
+                array_data.extend_from_slice(body);
+            }
+
+            let mut data = Vec::new();
+            data.extend_from_slice(&pad_left_32(&0u64.to_be_bytes())); // blockNumber
+            data.extend_from_slice(&pad_left_32(&64u64.to_be_bytes())); // offset to array
+            data.extend_from_slice(&array_data);
+            data
+        }
+
+        #[test]
+        fn decode_aggregate_result_round_trips_each_element() {
+            let data = encode_bytes_array_result(&[&[0xaa, 0xbb], &[0xcc]]);
+            let decoded = decode_aggregate_result(&data);
+            assert_eq!(decoded, vec![vec![0xaa, 0xbb], vec![0xcc]]);
+        }
+
+        #[test]
+        fn decode_aggregate_result_bails_out_on_an_implausible_length() {
+            // word0 = blockNumber, word1 = offset to array (64, right
+            // after the two head words), word2 (at that offset) = a
+            // length far larger than the data could actually back.
+            let mut data = pad_left_32(&0u64.to_be_bytes()).to_vec();
+            data.extend_from_slice(&pad_left_32(&64u64.to_be_bytes()));
+            data.extend_from_slice(&pad_left_32(&u64::MAX.to_be_bytes()));
+            assert_eq!(decode_aggregate_result(&data), Vec::<Vec<u8>>::new());
+        }
+
+        #[test]
+        fn decode_uint_reads_a_big_endian_word() {
+            assert_eq!(
+                decode_uint(&pad_left_32(&1_000_000u64.to_be_bytes())),
+                1_000_000
+            );
+        }
+    }
+
+    /// Decodes `aggregate`'s `(uint256 blockNumber, bytes[] returnData)`
+    /// result into each call's raw (still ABI-encoded) return data.
+    fn decode_aggregate_result(data: &[u8]) -> Vec<Vec<u8>> {
+        let array_start = word_at(data, 32) as usize;
+        let len = word_at(data, array_start) as usize;
+
+        // A corrupt or adversarial response could claim an element count
+        // far larger than the data backing it; bail out instead of
+        // iterating on bogus offsets below.
+        if len > data.len() / 32 {
+            return Vec::new();
+        }
+
+        // Every element must decode to *something* (even if empty) so
+        // the positional pairing `get_accounts_batched` relies on to
+        // match each result back to its address doesn't shift when one
+        // entry's offsets are bogus.
+        (0..len)
+            .map(|i| {
+                let head = match array_start
+                    .checked_add(32)
+                    .and_then(|p| p.checked_add(i * 32))
+                {
+                    Some(h) => h,
+                    None => return Vec::new(),
+                };
+                let elem_offset = word_at(data, head) as usize;
+                let elem_start = match array_start
+                    .checked_add(32)
+                    .and_then(|p| p.checked_add(elem_offset))
+                {
+                    Some(s) => s,
+                    None => return Vec::new(),
+                };
+                let elem_len = word_at(data, elem_start) as usize;
+                match elem_start.checked_add(32) {
+                    Some(data_start) => safe_slice(data, data_start, elem_len),
+                    None => Vec::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// Decodes a static `uint256` return value.
+    fn decode_uint(word: &[u8]) -> u128 {
+        let mut buf = [0u8; 16];
+        let start = word.len().saturating_sub(16);
+        buf[16 - (word.len() - start)..].copy_from_slice(&word[start..]);
+        u128::from_be_bytes(buf)
+    }
+
+    /// Fetches balance and bytecode for every address in `addrs`. When
+    /// `client`'s chain has a registered Multicall deployment, balances
+    /// are fetched in a single aggregated `eth_call`; otherwise (and for
+    /// bytecode either way) it falls back to sequential Etherscan calls
+    /// per address.
+    ///
+    /// Bytecode is never folded into the `aggregate` batch: the
+    /// canonical Multicall1 deployments used here expose no `getCode`
+    /// function, and `aggregate` `require`s every sub-call to succeed —
+    /// including one would make the whole batched call revert.
+    pub fn get_accounts(
+        client: &etherscan::Client,
+        addrs: &[eth::Address],
+    ) -> Result<Vec<eth::Account>, Errors> {
+        match multicall_address(client.chain()) {
+            Some(multicall) => get_accounts_batched(client, multicall, addrs),
+            None => get_accounts_sequential(client, addrs),
+        }
+    }
+
+    fn get_accounts_batched(
+        client: &etherscan::Client,
+        multicall: &str,
+        addrs: &[eth::Address],
+    ) -> Result<Vec<eth::Account>, Errors> {
+        let target = hex::decode(multicall);
+        let calls: Vec<(Vec<u8>, Vec<u8>)> = addrs
+            .iter()
+            .map(|addr| {
+                let mut balance_call = hex::decode(GET_ETH_BALANCE_SELECTOR);
+                balance_call.extend_from_slice(&encode_address_arg(addr));
+                (target.clone(), balance_call)
+            })
+            .collect();
+
+        let calldata = encode_aggregate(&calls);
+        let raw = client.eth_call(multicall, &hex::encode(&calldata))?;
+        let results = decode_aggregate_result(&raw);
+
+        addrs
+            .iter()
+            .enumerate()
+            .map(|(i, addr)| {
+                let balance = results.get(i).map(|w| decode_uint(w)).unwrap_or(0);
+                let code = client.get_code(addr)?;
+                Ok(eth::Account::new(addr.clone(), 0, balance as usize, code))
+            })
+            .collect()
+    }
+
+    fn get_accounts_sequential(
+        client: &etherscan::Client,
+        addrs: &[eth::Address],
+    ) -> Result<Vec<eth::Account>, Errors> {
+        addrs
+            .iter()
+            .map(|addr| {
+                let balance = client.get_balance(addr)?;
+                let code = client.get_code(addr)?;
+                Ok(eth::Account::new(addr.clone(), 0, balance as usize, code))
+            })
+            .collect()
     }
 }
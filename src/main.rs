@@ -4,7 +4,7 @@ use std::env;
 fn main() {
     let etherscan_apikey = env::var("ETHERSCAN_API").ok().unwrap();
 
-    let etherscan = etherscan::Client::new(etherscan_apikey.to_owned());
+    let etherscan = etherscan::Client::new(etherscan_apikey.to_owned(), etherscan::Chain::Mainnet);
 
     let addr: eth::Address = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
         .try_into()